@@ -60,6 +60,37 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Modem status signals read from the Flag Register.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct ModemStatus: u16 {
+        /// Clear to send.
+        const CTS = 1 << 0;
+        /// Data set ready.
+        const DSR = 1 << 1;
+        /// Data carrier detect.
+        const DCD = 1 << 2;
+        /// Ring indicator.
+        const RI = 1 << 8;
+    }
+}
+
+bitflags! {
+    /// Flags from the DMA Control Register.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct Dma: u8 {
+        /// Receive DMA enable.
+        const RXDMAE = 1 << 0;
+        /// Transmit DMA enable.
+        const TXDMAE = 1 << 1;
+        /// DMA on error. When set, a receive DMA request is disabled after an error is reported
+        /// in the receive FIFO.
+        const DMAONERR = 1 << 2;
+    }
+}
+
 bitflags! {
     /// Flags from the UART Receive Status Register / Error Clear Register.
     #[repr(transparent)]
@@ -76,6 +107,30 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Flags from the Line Control Register.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct LineControl: u8 {
+        /// Send break.
+        const BRK = 1 << 0;
+        /// Parity enable.
+        const PEN = 1 << 1;
+        /// Even Parity Select.
+        const EPS = 1 << 2;
+        /// Two Stop Bits Select.
+        const STP2 = 1 << 3;
+        /// Enable FIFOs.
+        const FEN = 1 << 4;
+        /// Word length, bit 0. Bits 6:5 together select the word length.
+        const WLEN0 = 1 << 5;
+        /// Word length, bit 1. Bits 6:5 together select the word length.
+        const WLEN1 = 1 << 6;
+        /// Stick Parity Select.
+        const SPS = 1 << 7;
+    }
+}
+
 bitflags! {
     /// Flags from the UART Control Register.
     #[repr(transparent)]
@@ -109,6 +164,36 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// Interrupt sources, shared by the IMSC, RIS, MIS and ICR registers.
+    #[repr(transparent)]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct Interrupts: u16 {
+        /// nUARTRI modem interrupt.
+        const RIMIM = 1 << 0;
+        /// nUARTCTS modem interrupt.
+        const CTSMIM = 1 << 1;
+        /// nUARTDCD modem interrupt.
+        const DCDMIM = 1 << 2;
+        /// nUARTDSR modem interrupt.
+        const DSRMIM = 1 << 3;
+        /// Receive interrupt.
+        const RXIM = 1 << 4;
+        /// Transmit interrupt.
+        const TXIM = 1 << 5;
+        /// Receive timeout interrupt.
+        const RTIM = 1 << 6;
+        /// Framing error interrupt.
+        const FEIM = 1 << 7;
+        /// Parity error interrupt.
+        const PEIM = 1 << 8;
+        /// Break error interrupt.
+        const BEIM = 1 << 9;
+        /// Overrun error interrupt.
+        const OEIM = 1 << 10;
+    }
+}
+
 #[repr(C, align(4))]
 struct Registers {
     /// Data Register.
@@ -130,7 +215,7 @@ struct Registers {
     fbrd: u8,
     _reserved5: [u8; 3],
     /// Line Control Register.
-    lcr_h: u8,
+    lcr_h: LineControl,
     _reserved6: [u8; 3],
     /// Control Register.
     cr: Control,
@@ -139,19 +224,19 @@ struct Registers {
     ifls: u8,
     _reserved8: [u8; 3],
     /// Interrupt Mask Set/Clear Register.
-    imsc: u16,
+    imsc: Interrupts,
     _reserved9: [u8; 2],
     /// Raw Interrupt Status Register.
-    ris: u16,
+    ris: Interrupts,
     _reserved10: [u8; 2],
     /// Masked Interrupt Status Register.
-    mis: u16,
+    mis: Interrupts,
     _reserved11: [u8; 2],
     /// Interrupt Clear Register.
-    icr: u16,
+    icr: Interrupts,
     _reserved12: [u8; 2],
     /// DMA Control Register.
-    dmacr: u8,
+    dmacr: Dma,
     _reserved13: [u8; 3],
 }
 
@@ -181,10 +266,197 @@ impl embedded_io::Error for Error {
     }
 }
 
+/// Number of data bits per character.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum DataBits {
+    /// 5 data bits.
+    Five,
+    /// 6 data bits.
+    Six,
+    /// 7 data bits.
+    Seven,
+    /// 8 data bits.
+    #[default]
+    Eight,
+}
+
+impl DataBits {
+    fn word_length_bits(self) -> LineControl {
+        match self {
+            Self::Five => LineControl::empty(),
+            Self::Six => LineControl::WLEN0,
+            Self::Seven => LineControl::WLEN1,
+            Self::Eight => LineControl::WLEN0.union(LineControl::WLEN1),
+        }
+    }
+}
+
+/// Parity checking mode.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Parity {
+    /// No parity bit is sent.
+    #[default]
+    None,
+    /// Even parity.
+    Even,
+    /// Odd parity.
+    Odd,
+}
+
+/// Number of stop bits per character.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum StopBits {
+    /// 1 stop bit.
+    #[default]
+    One,
+    /// 2 stop bits.
+    Two,
+}
+
+/// Line configuration for a PL011 UART, programmed into the Line Control Register by
+/// [`Uart::init`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Config {
+    /// Number of data bits per character.
+    pub data_bits: DataBits,
+    /// Parity checking mode.
+    pub parity: Parity,
+    /// Number of stop bits per character.
+    pub stop_bits: StopBits,
+    /// Whether to enable the transmit and receive FIFOs.
+    pub fifo_enable: bool,
+}
+
+impl Config {
+    fn line_control(self) -> LineControl {
+        let mut lcr_h = self.data_bits.word_length_bits();
+        if self.fifo_enable {
+            lcr_h |= LineControl::FEN;
+        }
+        if self.stop_bits == StopBits::Two {
+            lcr_h |= LineControl::STP2;
+        }
+        match self.parity {
+            Parity::None => {}
+            Parity::Even => lcr_h |= LineControl::PEN | LineControl::EPS,
+            Parity::Odd => lcr_h |= LineControl::PEN,
+        }
+        lcr_h
+    }
+}
+
+/// Extracts the received byte from a Data Register value, or the error it indicates.
+fn decode_data(data: u16) -> Result<u8, Error> {
+    let error_status = Data::from_bits_truncate(data);
+    if error_status.contains(Data::FE) {
+        Err(Error::Framing)
+    } else if error_status.contains(Data::PE) {
+        Err(Error::Parity)
+    } else if error_status.contains(Data::BE) {
+        Err(Error::Break)
+    } else if error_status.contains(Data::OE) {
+        Err(Error::Overrun)
+    } else {
+        Ok(data as u8)
+    }
+}
+
+/// Blocks until at least one byte is available, then drains the RX FIFO into `buf` until either
+/// the FIFO is empty or `buf` is full, returning the number of bytes read.
+///
+/// If `pending_error` holds an error left over from a previous call, it is returned immediately
+/// without reading anything. If an error flag is encountered partway through a drain, the bytes
+/// read so far are returned and the error is stashed in `pending_error` to be returned by the
+/// next call; if it is the very first byte read, the error is returned directly.
+///
+/// # Safety
+///
+/// `registers` must point to the control registers of a PL011 device which is appropriately
+/// mapped, as promised by the caller of `Uart::new`.
+unsafe fn drain_rx_fifo(
+    registers: *mut Registers,
+    pending_error: &mut Option<Error>,
+    buf: &mut [u8],
+) -> Result<usize, Error> {
+    if let Some(error) = pending_error.take() {
+        return Err(error);
+    }
+    if buf.is_empty() {
+        return Ok(0);
+    }
+
+    // Block until at least one byte has arrived.
+    loop {
+        // SAFETY: `registers` is valid, as promised by the caller.
+        let flags = unsafe { addr_of!((*registers).fr).read_volatile() };
+        if !flags.contains(Flags::RXFE) {
+            break;
+        }
+        spin_loop();
+    }
+
+    // Drain the rest of the FIFO into `buf` without blocking further.
+    let mut count = 0;
+    while count < buf.len() {
+        // SAFETY: `registers` is valid, as promised by the caller.
+        let flags = unsafe { addr_of!((*registers).fr).read_volatile() };
+        if flags.contains(Flags::RXFE) {
+            break;
+        }
+        // SAFETY: `registers` is valid, as promised by the caller.
+        let data = unsafe { addr_of!((*registers).dr).read_volatile() };
+        match decode_data(data) {
+            Ok(byte) => {
+                buf[count] = byte;
+                count += 1;
+            }
+            Err(error) if count > 0 => {
+                *pending_error = Some(error);
+                return Ok(count);
+            }
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(count)
+}
+
+/// FIFO interrupt trigger level, expressed as a fraction of the FIFO depth, for use with
+/// [`Uart::set_fifo_levels`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum FifoLevel {
+    /// Trigger when the FIFO is 1/8 full (almost empty, for receive; almost full, for transmit).
+    OneEighth,
+    /// Trigger when the FIFO is 1/4 full.
+    OneQuarter,
+    /// Trigger when the FIFO is 1/2 full.
+    #[default]
+    Half,
+    /// Trigger when the FIFO is 3/4 full.
+    ThreeQuarters,
+    /// Trigger when the FIFO is 7/8 full.
+    SevenEighths,
+}
+
+impl FifoLevel {
+    fn bits(self) -> u8 {
+        match self {
+            Self::OneEighth => 0b000,
+            Self::OneQuarter => 0b001,
+            Self::Half => 0b010,
+            Self::ThreeQuarters => 0b011,
+            Self::SevenEighths => 0b100,
+        }
+    }
+}
+
 /// Driver for a PL011 UART.
 #[derive(Debug)]
 pub struct Uart {
     registers: *mut Registers,
+    /// An error encountered while draining the RX FIFO during a previous `read` call, which
+    /// follows some bytes that were returned successfully, and so must be reported on the next
+    /// call to `read` instead.
+    pending_error: Option<Error>,
 }
 
 impl Uart {
@@ -199,6 +471,7 @@ impl Uart {
     pub unsafe fn new(base_address: *mut u32) -> Self {
         Self {
             registers: base_address as *mut Registers,
+            pending_error: None,
         }
     }
 
@@ -206,8 +479,10 @@ impl Uart {
     ///
     /// clock: Uart clock in Hz.
     /// baud_rate: Baud rate.
-    pub fn init(&mut self, clock: u32, baud_rate: u32) {
-        let divisor = (clock << 2) / baud_rate;
+    /// config: Line configuration (data bits, parity, stop bits, FIFOs).
+    pub fn init(&mut self, clock: u32, baud_rate: u32, config: Config) {
+        // Round to the nearest 1/64 rather than truncating, for a more accurate baud rate.
+        let divisor = (4 * clock + baud_rate / 2) / baud_rate;
 
         // SAFETY: self.registers points to the control registers of a PL011 device which is
         // appropriately mapped, as promised by the caller of `Uart::new`.
@@ -221,6 +496,8 @@ impl Uart {
             // Program Fractional Baud Rate.
             addr_of_mut!((*self.registers).fbrd)
                 .write_volatile((divisor & 0x3F).try_into().unwrap());
+            // Program the line control, e.g. data bits, parity, stop bits and FIFOs.
+            addr_of_mut!((*self.registers).lcr_h).write_volatile(config.line_control());
             // Clear any pending errors.
             addr_of_mut!((*self.registers).rsr).write_volatile(ReceiveStatus::empty());
             // Enable UART.
@@ -264,20 +541,274 @@ impl Uart {
             // SAFETY: self.registers points to the control registers of a PL011 device which is
             // appropriately mapped, as promised by the caller of `Uart::new`.
             let data = unsafe { addr_of!((*self.registers).dr).read_volatile() };
-            let error_status = Data::from_bits_truncate(data);
-            if error_status.contains(Data::FE) {
-                return Err(Error::Framing);
-            }
-            if error_status.contains(Data::PE) {
-                return Err(Error::Parity);
-            }
-            if error_status.contains(Data::BE) {
-                return Err(Error::Break);
-            }
-            if error_status.contains(Data::OE) {
-                return Err(Error::Overrun);
-            }
-            Ok(Some(data as u8))
+            decode_data(data).map(Some)
+        }
+    }
+
+    /// Enables or disables RTS/CTS hardware flow control.
+    pub fn set_hardware_flow_control(&mut self, rts: bool, cts: bool) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let mut cr = addr_of_mut!((*self.registers).cr).read_volatile();
+            cr.set(Control::RTSEN, rts);
+            cr.set(Control::CTSEN, cts);
+            addr_of_mut!((*self.registers).cr).write_volatile(cr);
+        }
+    }
+
+    /// Manually asserts or deasserts the Request To Send (RTS) modem line.
+    ///
+    /// This has no effect while RTS hardware flow control is enabled; see
+    /// [`set_hardware_flow_control`](Self::set_hardware_flow_control).
+    pub fn set_rts(&mut self, asserted: bool) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let mut cr = addr_of_mut!((*self.registers).cr).read_volatile();
+            cr.set(Control::RTS, asserted);
+            addr_of_mut!((*self.registers).cr).write_volatile(cr);
+        }
+    }
+
+    /// Manually asserts or deasserts the Data Transmit Ready (DTR) modem line.
+    pub fn set_dtr(&mut self, asserted: bool) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let mut cr = addr_of_mut!((*self.registers).cr).read_volatile();
+            cr.set(Control::DTR, asserted);
+            addr_of_mut!((*self.registers).cr).write_volatile(cr);
+        }
+    }
+
+    /// Returns the current state of the CTS, DSR, DCD and RI modem status lines.
+    pub fn modem_status(&self) -> ModemStatus {
+        ModemStatus::from_bits_truncate(self.flags().bits())
+    }
+
+    /// Enables or disables internal loopback mode.
+    ///
+    /// While loopback is enabled the transmit serial output is fed back into the receive serial
+    /// input internally, instead of being driven onto the external pins. This allows firmware to
+    /// run a power-on self-test by writing a known pattern with [`write_byte`](Self::write_byte)
+    /// and confirming it reads back via [`read_byte`](Self::read_byte), without any external
+    /// wiring. `UARTEN`, `TXE` and `RXE` must be set (as they are after [`init`](Self::init)) for
+    /// the test to work, and loopback should be disabled again before normal operation.
+    pub fn set_loopback(&mut self, enabled: bool) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let mut cr = addr_of_mut!((*self.registers).cr).read_volatile();
+            cr.set(Control::LBE, enabled);
+            addr_of_mut!((*self.registers).cr).write_volatile(cr);
+        }
+    }
+
+    /// Configures the UART to make DMA requests, for driving it from a platform DMA controller.
+    ///
+    /// `tx` and `rx` enable DMA requests for the transmit and receive FIFOs respectively. If
+    /// `dma_on_error` is true, a receive DMA request is automatically disabled once an error is
+    /// reported in the receive FIFO, so software can intervene instead of DMAing the bad data.
+    pub fn set_dma(&mut self, tx: bool, rx: bool, dma_on_error: bool) {
+        let mut dmacr = Dma::empty();
+        dmacr.set(Dma::TXDMAE, tx);
+        dmacr.set(Dma::RXDMAE, rx);
+        dmacr.set(Dma::DMAONERR, dma_on_error);
+
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            addr_of_mut!((*self.registers).dmacr).write_volatile(dmacr);
+        }
+    }
+
+    /// Sets the FIFO watermark levels at which the receive and transmit interrupts (and DMA
+    /// requests) trigger.
+    pub fn set_fifo_levels(&mut self, rx: FifoLevel, tx: FifoLevel) {
+        let ifls = tx.bits() | (rx.bits() << 3);
+
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            addr_of_mut!((*self.registers).ifls).write_volatile(ifls);
+        }
+    }
+
+    /// Enables the given interrupts, leaving the others unaffected.
+    pub fn enable_interrupts(&mut self, interrupts: Interrupts) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let imsc = addr_of_mut!((*self.registers).imsc).read_volatile();
+            addr_of_mut!((*self.registers).imsc).write_volatile(imsc | interrupts);
+        }
+    }
+
+    /// Disables the given interrupts, leaving the others unaffected.
+    pub fn disable_interrupts(&mut self, interrupts: Interrupts) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            let imsc = addr_of_mut!((*self.registers).imsc).read_volatile();
+            addr_of_mut!((*self.registers).imsc).write_volatile(imsc & !interrupts);
+        }
+    }
+
+    /// Returns the interrupts which are currently asserted and not masked out.
+    pub fn masked_interrupt_status(&self) -> Interrupts {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { addr_of!((*self.registers).mis).read_volatile() }
+    }
+
+    /// Returns the interrupts which are currently asserted, regardless of masking.
+    pub fn raw_interrupt_status(&self) -> Interrupts {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { addr_of!((*self.registers).ris).read_volatile() }
+    }
+
+    /// Clears the given pending interrupts.
+    pub fn clear_interrupts(&mut self, interrupts: Interrupts) {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            addr_of_mut!((*self.registers).icr).write_volatile(interrupts);
+        }
+    }
+
+    fn flags(&self) -> Flags {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { addr_of!((*self.registers).fr).read_volatile() }
+    }
+
+    /// Splits the UART driver into independent transmit and receive halves.
+    ///
+    /// This is useful for interrupt- or multi-core-driven designs, where the transmit and receive
+    /// sides are handled by different tasks or interrupt handlers. The only registers both halves
+    /// access are the data register, which the transmit half only writes and the receive half only
+    /// reads, and the flag register, which both only read, so this is safe to do.
+    pub fn split(self) -> (UartTx, UartRx) {
+        (
+            UartTx {
+                registers: self.registers,
+            },
+            UartRx {
+                registers: self.registers,
+                pending_error: self.pending_error,
+            },
+        )
+    }
+}
+
+/// Transmit half of a [`Uart`], created by [`Uart::split`].
+#[derive(Debug)]
+pub struct UartTx {
+    registers: *mut Registers,
+}
+
+impl UartTx {
+    /// Writes a single byte to the UART.
+    ///
+    /// This blocks until there is space in the transmit FIFO or holding register, but returns as
+    /// soon as the byte has been written to the transmit FIFO or holding register. It doesn't wait
+    /// for the byte to be sent.
+    pub fn write_byte(&mut self, byte: u8) {
+        // Wait until there is room in the TX buffer.
+        while self.flags().contains(Flags::TXFF) {
+            spin_loop();
+        }
+
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe {
+            // Write to the TX buffer.
+            addr_of_mut!((*self.registers).dr).write_volatile(u16::from(byte));
+        }
+    }
+
+    /// Returns whether the UART is currently transmitting data.
+    ///
+    /// This will be true immediately after calling [`write_byte`](Self::write_byte).
+    pub fn is_transmitting(&self) -> bool {
+        self.flags().contains(Flags::BUSY)
+    }
+
+    /// Reunites this transmit half with the given receive half to recover the original [`Uart`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rx` was not split from the same `Uart` as this `UartTx`.
+    pub fn reunite(self, rx: UartRx) -> Uart {
+        assert_eq!(self.registers, rx.registers);
+        Uart {
+            registers: self.registers,
+            pending_error: rx.pending_error,
+        }
+    }
+
+    fn flags(&self) -> Flags {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { addr_of!((*self.registers).fr).read_volatile() }
+    }
+}
+
+// SAFETY: `UartTx` just contains a pointer to device memory, and only ever writes the data
+// register and reads the flag register, which doesn't conflict with the accesses of `UartRx`.
+unsafe impl Send for UartTx {}
+
+impl ErrorType for UartTx {
+    type Error = Error;
+}
+
+impl Write for UartTx {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            Ok(0)
+        } else {
+            self.write_byte(buf[0]);
+            Ok(1)
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        while self.is_transmitting() {
+            spin_loop();
+        }
+        Ok(())
+    }
+}
+
+impl WriteReady for UartTx {
+    fn write_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.flags().contains(Flags::TXFF))
+    }
+}
+
+/// Receive half of a [`Uart`], created by [`Uart::split`].
+#[derive(Debug)]
+pub struct UartRx {
+    registers: *mut Registers,
+    /// An error encountered while draining the RX FIFO during a previous `read` call, which
+    /// follows some bytes that were returned successfully, and so must be reported on the next
+    /// call to `read` instead.
+    pending_error: Option<Error>,
+}
+
+impl UartRx {
+    /// Reads and returns a pending byte, or `None` if nothing has been
+    /// received.
+    pub fn read_byte(&mut self) -> Result<Option<u8>, Error> {
+        if self.flags().contains(Flags::RXFE) {
+            Ok(None)
+        } else {
+            // SAFETY: self.registers points to the control registers of a PL011 device which is
+            // appropriately mapped, as promised by the caller of `Uart::new`.
+            let data = unsafe { addr_of!((*self.registers).dr).read_volatile() };
+            decode_data(data).map(Some)
         }
     }
 
@@ -288,6 +819,28 @@ impl Uart {
     }
 }
 
+// SAFETY: `UartRx` just contains a pointer to device memory, and only ever reads the data
+// register and the flag register, which doesn't conflict with the accesses of `UartTx`.
+unsafe impl Send for UartRx {}
+
+impl ErrorType for UartRx {
+    type Error = Error;
+}
+
+impl Read for UartRx {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { drain_rx_fifo(self.registers, &mut self.pending_error, buf) }
+    }
+}
+
+impl ReadReady for UartRx {
+    fn read_ready(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.flags().contains(Flags::RXFE))
+    }
+}
+
 impl fmt::Write for Uart {
     fn write_str(&mut self, s: &str) -> fmt::Result {
         for c in s.as_bytes() {
@@ -334,16 +887,9 @@ impl WriteReady for Uart {
 
 impl Read for Uart {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
-        if buf.is_empty() {
-            return Ok(0);
-        }
-
-        loop {
-            if let Some(byte) = self.read_byte()? {
-                buf[0] = byte;
-                return Ok(1);
-            }
-        }
+        // SAFETY: self.registers points to the control registers of a PL011 device which is
+        // appropriately mapped, as promised by the caller of `Uart::new`.
+        unsafe { drain_rx_fifo(self.registers, &mut self.pending_error, buf) }
     }
 }
 